@@ -22,6 +22,8 @@
 //
 
 use std::mem;
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
 
 use audio::{SoundStatus, SoundBufferRef, SoundSource};
@@ -33,6 +35,40 @@ use csfml_system_sys::{sfBool, sfVector3f};
 use csfml_audio_sys as ffi;
 use ext::sf_bool_ext::SfBoolExt;
 
+/// Error returned when the C side fails to allocate a `Sound`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SoundCreationError;
+
+impl fmt::Display for SoundCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to create sfSound")
+    }
+}
+
+impl Error for SoundCreationError {}
+
+/// A time range, defined by a starting offset and a length.
+///
+/// This is used to describe a sub-range of a sound buffer, for example the
+/// region a `Sound` should loop over (see `Sound::set_loop_points`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeSpan {
+    /// The beginning of the time range.
+    pub offset: Time,
+    /// The length of the time range.
+    pub length: Time,
+}
+
+impl TimeSpan {
+    /// Create a new time range from an `offset` and a `length`.
+    pub fn new(offset: Time, length: Time) -> TimeSpan {
+        TimeSpan {
+            offset: offset,
+            length: length,
+        }
+    }
+}
+
 /// Regular sound that can be played in the audio environment.
 ///
 /// `Sound` is the type to use to play sounds.
@@ -63,39 +99,109 @@ use ext::sf_bool_ext::SfBoolExt;
 /// ```
 pub struct Sound<'s> {
     sound: *mut ffi::sfSound,
+    loop_start: Time,
+    loop_end: Time,
+    fading: bool,
+    fade_start_volume: f32,
+    fade_target: f32,
+    fade_elapsed: Time,
+    fade_duration: Time,
+    fade_stop_on_finish: bool,
+    prev_status: SoundStatus,
+    cb_prev_status: SoundStatus,
+    on_finished: Option<Box<dyn FnMut() + 's>>,
     buffer: PhantomData<&'s SoundBufferRef>,
 }
 
 impl<'s> Sound<'s> {
-    /// Create a new Sound
-    pub fn new() -> Sound<'s> {
-        let s = unsafe { ffi::sfSound_create() };
-        if s.is_null() {
-            panic!("sfSound_create returned null.")
-        } else {
-            Sound {
-                sound: s,
-                buffer: PhantomData,
-            }
+    /// Wrap a freshly created raw `sfSound` in a defaulted `Sound`.
+    fn from_raw(s: *mut ffi::sfSound) -> Sound<'s> {
+        Sound {
+            sound: s,
+            loop_start: Time::microseconds(0),
+            loop_end: Time::microseconds(0),
+            fading: false,
+            fade_start_volume: 0.,
+            fade_target: 0.,
+            fade_elapsed: Time::microseconds(0),
+            fade_duration: Time::microseconds(0),
+            fade_stop_on_finish: false,
+            prev_status: SoundStatus::Stopped,
+            cb_prev_status: SoundStatus::Stopped,
+            on_finished: None,
+            buffer: PhantomData,
         }
     }
 
-    /// Create a new Sound with a buffer
-    pub fn with_buffer(buffer: &SoundBufferRef) -> Sound {
+    /// Try to create a new Sound, returning an error if allocation fails.
+    pub fn try_new() -> Result<Sound<'s>, SoundCreationError> {
         let s = unsafe { ffi::sfSound_create() };
         if s.is_null() {
-            panic!("sfSound_create returned null.")
+            Err(SoundCreationError)
         } else {
-            unsafe {
-                ffi::sfSound_setBuffer(s, buffer as *const _ as _);
-            }
-            Sound {
-                sound: s,
-                buffer: PhantomData,
-            }
+            Ok(Sound::from_raw(s))
         }
     }
 
+    /// Try to create a new Sound with a buffer, returning an error if
+    /// allocation fails.
+    pub fn try_with_buffer(buffer: &'s SoundBufferRef)
+                           -> Result<Sound<'s>, SoundCreationError> {
+        let mut sound = Sound::try_new()?;
+        unsafe {
+            ffi::sfSound_setBuffer(sound.sound, buffer as *const _ as _);
+        }
+        Ok(sound)
+    }
+
+    /// Create a new Sound
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sound could not be created. See `try_new` for a fallible
+    /// alternative.
+    pub fn new() -> Sound<'s> {
+        Sound::try_new().expect("sfSound_create returned null.")
+    }
+
+    /// Create a new Sound with a buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sound could not be created. See `try_with_buffer` for a
+    /// fallible alternative.
+    pub fn with_buffer(buffer: &'s SoundBufferRef) -> Sound<'s> {
+        Sound::try_with_buffer(buffer).expect("sfSound_create returned null.")
+    }
+
+    /// Try to create a valid-but-muted Sound.
+    ///
+    /// This gives callers a uniform `Sound` to hand back when a real source
+    /// could not be configured, following the "never return a broken source"
+    /// approach: the sound is backed by a real `sfSound` with its volume set to
+    /// zero, so it is safe to use but produces no output.
+    ///
+    /// Note that a `Sound` always wraps a real `sfSound`; there is no way to
+    /// fabricate a source without the C side allocating one. Allocation can
+    /// therefore still fail, in which case the error is returned rather than
+    /// handing back a broken source.
+    pub fn try_silent() -> Result<Sound<'s>, SoundCreationError> {
+        let mut sound = Sound::try_new()?;
+        sound.set_volume(0.);
+        Ok(sound)
+    }
+
+    /// Create a valid-but-muted Sound.
+    ///
+    /// See `try_silent` for the details of what is and isn't guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if even the dummy source could not be allocated.
+    pub fn silent() -> Sound<'s> {
+        Sound::try_silent().expect("sfSound_create returned null.")
+    }
+
     /// Sets whether this sound should loop or not.
     pub fn set_looping(&mut self, looping: bool) {
         unsafe { ffi::sfSound_setLoop(self.sound, sfBool::from_bool(looping)) }
@@ -186,6 +292,289 @@ impl<'s> Sound<'s> {
             }
         }
     }
+
+    /// Sets the region of the attached buffer that the sound should loop over.
+    ///
+    /// Unlike `Music`, the underlying `sfSound` has no native loop-point support,
+    /// so the range is stored in the wrapper and enforced from `update()`, which
+    /// the caller must poll each frame while the sound is looping.
+    ///
+    /// `span.offset + span.length` is clamped to the duration of the attached
+    /// buffer. A zero-length span means "loop over the whole buffer".
+    pub fn set_loop_points(&mut self, span: TimeSpan) {
+        let duration = self.buffer().map(|b| b.duration()).unwrap_or_else(|| {
+            Time::microseconds(0)
+        });
+        if span.length == Time::microseconds(0) {
+            self.loop_start = Time::microseconds(0);
+            self.loop_end = duration;
+            return;
+        }
+        let mut end = span.offset + span.length;
+        if end > duration {
+            end = duration;
+        }
+        let mut start = span.offset;
+        if start > end {
+            start = end;
+        }
+        self.loop_start = start;
+        self.loop_end = end;
+    }
+
+    /// Gets the region of the attached buffer the sound loops over.
+    ///
+    /// See `set_loop_points`.
+    pub fn loop_points(&self) -> TimeSpan {
+        TimeSpan::new(self.loop_start, self.loop_end - self.loop_start)
+    }
+
+    /// Start ramping the volume toward `target_volume` over `duration`.
+    ///
+    /// The fade is non-blocking: it is advanced from `update()`, which the
+    /// caller must poll each frame. Starting a new fade while one is already in
+    /// progress re-anchors the ramp at the current volume. A zero `duration`
+    /// jumps straight to the target.
+    pub fn fade_to(&mut self, target_volume: f32, duration: Time) {
+        self.fade_start_volume = self.volume();
+        self.fade_target = target_volume;
+        self.fade_elapsed = Time::microseconds(0);
+        self.fade_duration = duration;
+        self.fade_stop_on_finish = false;
+        self.fading = true;
+        if duration == Time::microseconds(0) {
+            self.finish_fade();
+        }
+    }
+
+    /// Fade the volume out to zero over `duration`, then stop the sound.
+    ///
+    /// Like `fade_to`, the ramp is advanced from `update()`. Once the fade
+    /// completes the sound is stopped.
+    pub fn fade_out_and_stop(&mut self, duration: Time) {
+        self.fade_to(0., duration);
+        self.fade_stop_on_finish = true;
+        if duration == Time::microseconds(0) {
+            self.finish_fade();
+        }
+    }
+
+    fn finish_fade(&mut self) {
+        self.set_volume(self.fade_target);
+        self.fading = false;
+        if self.fade_stop_on_finish {
+            self.stop();
+        }
+    }
+
+    /// Advance the per-frame wrapper state by `dt`.
+    ///
+    /// The caller should poll this once per frame. It enforces the configured
+    /// loop points (wrapping the playing offset back to `loop_start` once it
+    /// reaches `loop_end`) and advances any in-progress volume fade started
+    /// with `fade_to` or `fade_out_and_stop`.
+    pub fn update(&mut self, dt: Time) {
+        if self.loop_end != Time::microseconds(0) && self.is_looping() &&
+           self.status() == SoundStatus::Playing {
+            let offset = self.playing_offset();
+            if offset >= self.loop_end {
+                self.set_playing_offset(self.loop_start + (offset - self.loop_end));
+            }
+        }
+        if self.fading {
+            self.fade_elapsed = self.fade_elapsed + dt;
+            let t = if self.fade_duration == Time::microseconds(0) {
+                1.
+            } else {
+                (self.fade_elapsed.as_seconds() / self.fade_duration.as_seconds()).min(1.)
+            };
+            let volume = self.fade_start_volume +
+                         (self.fade_target - self.fade_start_volume) * t;
+            self.set_volume(volume);
+            if t >= 1. {
+                self.finish_fade();
+            }
+        }
+        // Drive the registered callback off its own cached status so polling
+        // `just_finished` independently still observes the same transition.
+        if self.on_finished.is_some() {
+            let status = self.status();
+            let finished = self.cb_prev_status == SoundStatus::Playing &&
+                           status == SoundStatus::Stopped;
+            self.cb_prev_status = status;
+            if finished {
+                if let Some(mut callback) = self.on_finished.take() {
+                    callback();
+                    self.on_finished = Some(callback);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` exactly once, on the tick where the sound transitions
+    /// from `Playing` to `Stopped`.
+    ///
+    /// This is a polling stand-in for a native end-of-playback callback:
+    /// call it once per frame and act when it returns `true`. The transition is
+    /// detected against a status cache dedicated to this method, so driving
+    /// `update()` (as the loop-point and fade features require) does not consume
+    /// the transition out from under it.
+    pub fn just_finished(&mut self) -> bool {
+        let status = self.status();
+        let finished = self.prev_status == SoundStatus::Playing &&
+                       status == SoundStatus::Stopped;
+        self.prev_status = status;
+        finished
+    }
+
+    /// Register a closure to be called each time the sound finishes playing.
+    ///
+    /// The closure is invoked from `update()` on every tick where the sound
+    /// transitions from `Playing` to `Stopped`, and stays registered afterwards.
+    /// Registering a new closure replaces the previous one. This lets users chain sounds or
+    /// trigger game events without running their own status-watch loop.
+    pub fn on_finished<F: FnMut() + 's>(&mut self, f: F) {
+        self.on_finished = Some(Box::new(f));
+    }
+}
+
+/// Fluent builder for configuring and spawning a `Sound` in a single expression.
+///
+/// Modeled on rg3d-sound's `GenericSourceBuilder`, this collects the parameters
+/// that would otherwise require a chain of mutable setter calls and applies them
+/// all in `build`.
+///
+/// # Usage example
+///
+/// ```no_run
+/// use sfml::audio::{SoundBuilder, SoundBuffer, SoundStatus};
+///
+/// let buffer = SoundBuffer::from_file("sound.wav").unwrap();
+/// let sound = SoundBuilder::new()
+///     .with_buffer(&buffer)
+///     .with_volume(50.)
+///     .with_looping(true)
+///     .with_status(SoundStatus::Playing)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct SoundBuilder<'s> {
+    buffer: Option<&'s SoundBufferRef>,
+    pitch: Option<f32>,
+    volume: Option<f32>,
+    looping: Option<bool>,
+    position: Option<Vector3f>,
+    relative_to_listener: Option<bool>,
+    min_distance: Option<f32>,
+    attenuation: Option<f32>,
+    playing_offset: Option<Time>,
+    status: Option<SoundStatus>,
+}
+
+impl<'s> SoundBuilder<'s> {
+    /// Create a new, empty `SoundBuilder`.
+    pub fn new() -> SoundBuilder<'s> {
+        SoundBuilder::default()
+    }
+
+    /// Set the buffer containing the audio data to play.
+    pub fn with_buffer(mut self, buffer: &'s SoundBufferRef) -> SoundBuilder<'s> {
+        self.buffer = Some(buffer);
+        self
+    }
+
+    /// Set the pitch.
+    pub fn with_pitch(mut self, pitch: f32) -> SoundBuilder<'s> {
+        self.pitch = Some(pitch);
+        self
+    }
+
+    /// Set the volume.
+    pub fn with_volume(mut self, volume: f32) -> SoundBuilder<'s> {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Set whether the sound should loop.
+    pub fn with_looping(mut self, looping: bool) -> SoundBuilder<'s> {
+        self.looping = Some(looping);
+        self
+    }
+
+    /// Set the 3D position of the sound in the audio scene.
+    pub fn with_position(mut self, position: Vector3f) -> SoundBuilder<'s> {
+        self.position = Some(position);
+        self
+    }
+
+    /// Set whether the sound's position is relative to the listener.
+    pub fn with_relative_to_listener(mut self, relative: bool) -> SoundBuilder<'s> {
+        self.relative_to_listener = Some(relative);
+        self
+    }
+
+    /// Set the minimum distance.
+    pub fn with_min_distance(mut self, distance: f32) -> SoundBuilder<'s> {
+        self.min_distance = Some(distance);
+        self
+    }
+
+    /// Set the attenuation factor.
+    pub fn with_attenuation(mut self, attenuation: f32) -> SoundBuilder<'s> {
+        self.attenuation = Some(attenuation);
+        self
+    }
+
+    /// Set the initial playing position.
+    pub fn with_playing_offset(mut self, offset: Time) -> SoundBuilder<'s> {
+        self.playing_offset = Some(offset);
+        self
+    }
+
+    /// Set the initial status.
+    ///
+    /// If `SoundStatus::Playing` is requested, `build` calls `play()` before
+    /// returning. Any other status has no effect: the sound is left stopped.
+    pub fn with_status(mut self, status: SoundStatus) -> SoundBuilder<'s> {
+        self.status = Some(status);
+        self
+    }
+
+    /// Create the configured `Sound`, applying every accumulated parameter.
+    pub fn build(self) -> Sound<'s> {
+        let mut sound = match self.buffer {
+            Some(buffer) => Sound::with_buffer(buffer),
+            None => Sound::new(),
+        };
+        if let Some(pitch) = self.pitch {
+            sound.set_pitch(pitch);
+        }
+        if let Some(volume) = self.volume {
+            sound.set_volume(volume);
+        }
+        if let Some(looping) = self.looping {
+            sound.set_looping(looping);
+        }
+        if let Some(position) = self.position {
+            sound.set_position(&position);
+        }
+        if let Some(relative) = self.relative_to_listener {
+            sound.set_relative_to_listener(relative);
+        }
+        if let Some(distance) = self.min_distance {
+            sound.set_min_distance(distance);
+        }
+        if let Some(attenuation) = self.attenuation {
+            sound.set_attenuation(attenuation);
+        }
+        if let Some(offset) = self.playing_offset {
+            sound.set_playing_offset(offset);
+        }
+        if self.status == Some(SoundStatus::Playing) {
+            sound.play();
+        }
+        sound
+    }
 }
 
 impl<'a> Default for Sound<'a> {
@@ -202,9 +591,23 @@ impl<'s> Clone for Sound<'s> {
         } else {
             Sound {
                 sound: s,
+                loop_start: self.loop_start,
+                loop_end: self.loop_end,
+                fading: self.fading,
+                fade_start_volume: self.fade_start_volume,
+                fade_target: self.fade_target,
+                fade_elapsed: self.fade_elapsed,
+                fade_duration: self.fade_duration,
+                fade_stop_on_finish: self.fade_stop_on_finish,
+                prev_status: self.prev_status,
+                cb_prev_status: self.cb_prev_status,
+                // A boxed closure cannot be cloned; the copy starts without one.
+                on_finished: None,
                 buffer: self.buffer,
             }
         }
+        // NB: `Clone` cannot surface a `Result`, so this keeps panicking; use
+        // `try_with_buffer` + `set_*` when a fallible copy is required.
     }
 }
 